@@ -0,0 +1,148 @@
+/// Batch companion to [`StatWindow`](crate::StatWindow): a mergeable
+/// accumulator for full-series statistics. Each chunk of a large series
+/// can be folded independently (e.g. on its own thread) into a
+/// `StatAccumulator`, and the per-chunk accumulators combined afterwards
+/// with [`merge`](StatAccumulator::merge) into the statistics of the
+/// whole series. Unlike `StatWindow` this has no notion of a sliding
+/// window; it only ever grows.
+#[derive(Default, Clone, Copy)]
+pub struct StatAccumulator {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl StatAccumulator {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Fold a new value into the accumulator.
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Combine another accumulator into this one, as if every value
+    /// folded into `other` had instead been pushed here directly.
+    ///
+    /// Implements Chan's parallel variance formula, so independently
+    /// accumulated chunks can be merged in any order.
+    pub fn merge(&mut self, other: &StatAccumulator) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let n = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let new_mean = self.mean + delta * other.count as f64 / n as f64;
+        let new_m2 =
+            self.m2 + other.m2 + delta * delta * (self.count as f64) * (other.count as f64) / n as f64;
+
+        self.count = n;
+        self.mean = new_mean;
+        self.m2 = new_m2;
+    }
+
+    /// Number of values folded into the accumulator so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean of all folded values.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Sample standard deviation of all folded values, or `0` with
+    /// fewer than two values.
+    pub fn stddev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        (self.m2 / (self.count - 1) as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_stat(arr: &[f64]) -> (f64, f64) {
+        if arr.len() == 1 {
+            return (arr[0], 0_f64);
+        }
+
+        let mean = arr.iter().fold(0_f64, |acc, &v| acc + v) / arr.len() as f64;
+        let var =
+            arr.iter().fold(0_f64, |acc, &v| acc + (v - mean).powi(2)) / (arr.len() - 1) as f64;
+        (mean, var.sqrt())
+    }
+
+    fn in_delta(v1: f64, v2: f64, delta: f64) -> bool {
+        (v1 - v2).abs() < delta
+    }
+
+    #[test]
+    fn single_chunk_matches_exact() {
+        let values: [f64; 6] = [1.0, 2.0, 4.0, 4.0, 7.2, 12.5];
+
+        let mut acc = StatAccumulator::new();
+        values.iter().for_each(|&v| acc.push(v));
+
+        let (em, es) = exact_stat(&values);
+        assert!(in_delta(em, acc.mean(), 1e-12));
+        assert!(in_delta(es, acc.stddev(), 1e-12));
+        assert_eq!(acc.count(), values.len());
+    }
+
+    #[test]
+    fn merging_two_chunks_matches_exact() {
+        let max_err = 1e-12;
+        let values: [f64; 10] = [1.0, 2.0, 4.0, 4.0, 7.2, 12.5, 2.8, 3.1, 65.3, 98.01];
+        let (left, right) = values.split_at(4);
+
+        let mut acc_left = StatAccumulator::new();
+        left.iter().for_each(|&v| acc_left.push(v));
+
+        let mut acc_right = StatAccumulator::new();
+        right.iter().for_each(|&v| acc_right.push(v));
+
+        acc_left.merge(&acc_right);
+
+        let (em, es) = exact_stat(&values);
+        assert!(
+            in_delta(em, acc_left.mean(), max_err),
+            "mean => exact: {}, merged: {}",
+            em,
+            acc_left.mean()
+        );
+        assert!(
+            in_delta(es, acc_left.stddev(), max_err),
+            "stddev => exact: {}, merged: {}",
+            es,
+            acc_left.stddev()
+        );
+        assert_eq!(acc_left.count(), values.len());
+    }
+
+    #[test]
+    fn merging_with_empty_is_identity() {
+        let mut acc = StatAccumulator::new();
+        acc.push(1.0);
+        acc.push(2.0);
+
+        let empty = StatAccumulator::new();
+        acc.merge(&empty);
+
+        assert_eq!(acc.count(), 2);
+        assert!(in_delta(acc.mean(), 1.5, 1e-12));
+    }
+}
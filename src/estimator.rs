@@ -0,0 +1,784 @@
+use std::collections::VecDeque;
+
+use num_traits::{Float, FromPrimitive};
+
+/// Instant buffer statistics as a result of adding new value.
+pub struct InstantStat<T> {
+    pub mean: T,
+    pub stddev: T,
+    pub min: T,
+    pub max: T,
+    /// Standardized third moment, only computed when the window was
+    /// created with [`StatWindow::with_moments`].
+    pub skewness: Option<T>,
+    /// Standardized fourth moment minus 3, only computed when the
+    /// window was created with [`StatWindow::with_moments`].
+    pub excess_kurtosis: Option<T>,
+}
+
+/// Special buffer for computing second-order statistics
+/// efficiently in the sliding window.
+///
+/// Generic over the float type `T`, so windows can be kept in `f64`
+/// (the default via type inference) or `f32` when memory is tight.
+pub struct StatWindow<T> {
+    values: Vec<T>,
+    idx: usize,
+    count: usize,
+    mean: T,
+    var_sum: T,
+
+    // monotonic counter identifying elements independently of the
+    // circular buffer position, used to age out the deques below
+    pos: u64,
+    // (absolute_index, value) pairs; front is always the current extremum
+    max_deque: VecDeque<(u64, T)>,
+    min_deque: VecDeque<(u64, T)>,
+
+    // third and fourth central moment sums, maintained only when
+    // `moments_enabled` so the plain mean/stddev path stays free of
+    // the extra arithmetic
+    moments_enabled: bool,
+    m3: T,
+    m4: T,
+
+    // when `nan_aware`, NaN pushes are kept in `values` (so the window
+    // still ages them out normally) but excluded from mean/var_sum;
+    // `valid_count` is the divisor for those moments instead of `count`
+    nan_aware: bool,
+    valid_count: usize,
+
+    // last z-score produced by the `View` impl (crate::view), unused
+    // otherwise
+    pub(crate) last_z: T,
+}
+
+impl<T: Float> Default for StatWindow<T> {
+    fn default() -> Self {
+        StatWindow {
+            values: Vec::new(),
+            idx: 0,
+            count: 0,
+            mean: T::zero(),
+            var_sum: T::zero(),
+            pos: 0,
+            max_deque: VecDeque::new(),
+            min_deque: VecDeque::new(),
+            moments_enabled: false,
+            m3: T::zero(),
+            m4: T::zero(),
+            nan_aware: false,
+            valid_count: 0,
+            last_z: T::zero(),
+        }
+    }
+}
+
+impl<T: Float + FromPrimitive> StatWindow<T> {
+    /// Create empty buffer with fixed window size.
+    pub fn new(window_size: usize) -> Option<Self> {
+        // windows lesser than 2 elements are nonsense
+        if window_size < 2 {
+            return None;
+        }
+
+        let mut buf = Vec::with_capacity(window_size);
+        Vec::resize_with(&mut buf, window_size, T::zero);
+
+        Some(StatWindow {
+            values: buf,
+            // a deque holds at most the window plus the just-pushed
+            // element (before eviction), so it never has to grow later
+            max_deque: VecDeque::with_capacity(window_size + 1),
+            min_deque: VecDeque::with_capacity(window_size + 1),
+            ..Default::default()
+        })
+    }
+
+    /// Create empty buffer that additionally tracks skewness and excess
+    /// kurtosis on every push, at the cost of a bit more arithmetic per
+    /// element.
+    pub fn with_moments(window_size: usize) -> Option<Self> {
+        Self::new(window_size).map(|mut sw| {
+            sw.moments_enabled = true;
+            sw
+        })
+    }
+
+    /// Create empty buffer that treats NaN pushes as missing samples:
+    /// they still occupy a slot in the window (and age out normally),
+    /// but `mean`/`stddev` are computed over the finite samples only,
+    /// using a separate valid-sample count as the divisor.
+    pub fn with_nan_handling(window_size: usize) -> Option<Self> {
+        Self::new(window_size).map(|mut sw| {
+            sw.nan_aware = true;
+            sw
+        })
+    }
+
+    /// Add a new value to the buffer.
+    /// Method returns instant statistics of the buffer.
+    pub fn push(&mut self, value: T) -> InstantStat<T> {
+        // store new value in buffer
+        let ejected_value = self.values[self.idx];
+        self.values[self.idx] = value;
+        self.move_idx();
+
+        let was_full = self.count >= self.values.len();
+        if !was_full {
+            self.count += 1;
+        }
+
+        // compute statistics
+        let (display_mean, new_var_sum, new_m3, new_m4, stddev) = if self.nan_aware {
+            // push_nan_aware already updated self.mean/self.var_sum in place;
+            // the mean it returns is only the NaN-when-empty display value,
+            // so it must not be written back into self.mean below.
+            let (display_mean, var_sum, stddev) = self.push_nan_aware(value, ejected_value, was_full);
+            (display_mean, var_sum, self.m3, self.m4, stddev)
+        } else if !was_full {
+            if self.moments_enabled {
+                growing_moments(self.mean, self.var_sum, self.m3, self.m4, value, self.count)
+            } else {
+                let (mean, var_sum, stddev) =
+                    growing_phase(self.mean, self.var_sum, value, self.count);
+                (mean, var_sum, self.m3, self.m4, stddev)
+            }
+        } else if self.moments_enabled {
+            sliding_moments(
+                self.mean,
+                self.var_sum,
+                self.m3,
+                self.m4,
+                value,
+                ejected_value,
+                self.count,
+            )
+        } else {
+            let (mean, var_sum, stddev) =
+                sliding_phase(self.mean, self.var_sum, value, ejected_value, self.count);
+            (mean, var_sum, self.m3, self.m4, stddev)
+        };
+
+        if !self.nan_aware {
+            self.mean = display_mean;
+            self.var_sum = new_var_sum;
+        }
+        self.m3 = new_m3;
+        self.m4 = new_m4;
+
+        let pos = self.pos;
+        self.pos += 1;
+
+        if !self.nan_aware || !value.is_nan() {
+            push_monotonic(&mut self.max_deque, pos, value, |back, v| back <= v);
+            push_monotonic(&mut self.min_deque, pos, value, |back, v| back >= v);
+        }
+        evict_expired(&mut self.max_deque, pos, self.values.len());
+        evict_expired(&mut self.min_deque, pos, self.values.len());
+
+        let (skewness, excess_kurtosis) = if self.moments_enabled {
+            standardized_moments(new_var_sum, new_m3, new_m4, self.count)
+        } else {
+            (None, None)
+        };
+
+        InstantStat {
+            mean: display_mean,
+            stddev,
+            min: self.min_deque.front().map(|&(_, v)| v).unwrap_or(value),
+            max: self.max_deque.front().map(|&(_, v)| v).unwrap_or(value),
+            skewness,
+            excess_kurtosis,
+        }
+    }
+
+    fn move_idx(&mut self) {
+        let new_idx = (self.idx + 1) % self.values.len();
+        self.idx = new_idx;
+    }
+
+    // Update self.mean/self.var_sum over finite samples only, treating a
+    // NaN `value`/`ejected_value` as absent rather than poisoning the
+    // accumulators. Returns (display_mean, var_sum, stddev): self.mean
+    // stays the correct running mean (0 once empty) so later finite
+    // pushes keep working, while display_mean is NaN when no finite
+    // sample remains in the window, for InstantStat to surface as-is.
+    fn push_nan_aware(&mut self, value: T, ejected_value: T, was_full: bool) -> (T, T, T) {
+        let value_finite = !value.is_nan();
+        let ejected_finite = was_full && !ejected_value.is_nan();
+
+        match (ejected_finite, value_finite) {
+            (false, false) => {}
+            (true, false) => {
+                let (mean, var_sum) = welford_remove(self.mean, self.var_sum, ejected_value, self.valid_count);
+                self.mean = mean;
+                self.var_sum = var_sum;
+                self.valid_count -= 1;
+            }
+            (false, true) => {
+                self.valid_count += 1;
+                let (mean, var_sum) = welford_add(self.mean, self.var_sum, value, self.valid_count);
+                self.mean = mean;
+                self.var_sum = var_sum;
+            }
+            (true, true) => {
+                let (mean, var_sum) = welford_remove(self.mean, self.var_sum, ejected_value, self.valid_count);
+                let (mean, var_sum) = welford_add(mean, var_sum, value, self.valid_count);
+                self.mean = mean;
+                self.var_sum = var_sum;
+            }
+        }
+
+        let stddev = if self.valid_count >= 2 {
+            (self.var_sum / cast::<T>(self.valid_count as f64 - 1.0)).sqrt()
+        } else {
+            T::zero()
+        };
+        let mean = if self.valid_count == 0 {
+            T::nan()
+        } else {
+            self.mean
+        };
+
+        (mean, self.var_sum, stddev)
+    }
+}
+
+// Cast a literal through f64 into the generic float type.
+#[inline]
+fn cast<T: FromPrimitive>(x: f64) -> T {
+    T::from_f64(x).expect("literal always representable in T")
+}
+
+// Push a new (pos, value) pair into a monotonic deque, discarding
+// elements from the back that can never again become the extremum.
+// `dominates` decides whether the back element is superseded by the new
+// value (`<=` for the max deque, `>=` for the min deque).
+#[inline]
+fn push_monotonic<T: Copy>(
+    deque: &mut VecDeque<(u64, T)>,
+    pos: u64,
+    value: T,
+    dominates: impl Fn(T, T) -> bool,
+) {
+    while let Some(&(_, back_value)) = deque.back() {
+        if dominates(back_value, value) {
+            deque.pop_back();
+        } else {
+            break;
+        }
+    }
+    deque.push_back((pos, value));
+}
+
+// Drop front entries that have slid out of the window, i.e. whose
+// absolute index is no longer among the last `window_size` elements.
+#[inline]
+fn evict_expired<T>(deque: &mut VecDeque<(u64, T)>, pos: u64, window_size: usize) {
+    let window_size = window_size as u64;
+    while let Some(&(idx, _)) = deque.front() {
+        if idx + window_size <= pos {
+            deque.pop_front();
+        } else {
+            break;
+        }
+    }
+}
+
+// Welford's online algorithm for computing variance
+// in the growing array with O(1) complexity.
+#[inline]
+fn growing_phase<T: Float + FromPrimitive>(
+    mean: T,
+    var_sum: T,
+    new_element: T,
+    count: usize,
+) -> (T, T, T) {
+    if count < 2 {
+        return (new_element, T::zero(), T::zero());
+    }
+
+    let count_t: T = cast(count as f64);
+    let new_mean = mean + (new_element - mean) / count_t;
+    let new_var_sum = var_sum + (new_element - mean) * (new_element - new_mean);
+    let sample_variance = new_var_sum / (count_t - T::one());
+
+    (new_mean, new_var_sum, sample_variance.sqrt())
+}
+
+// Modified algorithm for efficient variance
+// computation in the sliding window.
+#[inline]
+fn sliding_phase<T: Float + FromPrimitive>(
+    mean: T,
+    var_sum: T,
+    new_element: T,
+    ejected_element: T,
+    count: usize,
+) -> (T, T, T) {
+    let count_t: T = cast(count as f64);
+    let new_mean = mean + (new_element - ejected_element) / count_t;
+    let new_var_sum = var_sum
+        + (new_element - ejected_element) * (new_element + ejected_element - mean - new_mean);
+    let sample_variance = new_var_sum / (count_t - T::one());
+
+    (new_mean, new_var_sum, sample_variance.sqrt())
+}
+
+// Welford add/remove restricted to mean/var_sum (M2), used by the
+// NaN-aware path where the divisor is the valid-sample count rather
+// than the window span. `n_new`/`n_old` must be the valid-sample count
+// after/before the respective operation.
+#[inline]
+fn welford_add<T: Float + FromPrimitive>(mean: T, var_sum: T, x: T, n_new: usize) -> (T, T) {
+    let n: T = cast(n_new as f64);
+    let delta = x - mean;
+    let new_mean = mean + delta / n;
+    let new_var_sum = var_sum + delta * (x - new_mean);
+
+    (new_mean, new_var_sum)
+}
+
+#[inline]
+fn welford_remove<T: Float + FromPrimitive>(mean: T, var_sum: T, x: T, n_old: usize) -> (T, T) {
+    if n_old <= 1 {
+        return (T::zero(), T::zero());
+    }
+
+    let n: T = cast(n_old as f64);
+    let delta_n = (x - mean) / (n - T::one());
+    let old_mean = mean - delta_n;
+    let delta = delta_n * n;
+    let term1 = delta * delta_n * (n - T::one());
+    let old_var_sum = var_sum - term1;
+
+    (old_mean, old_var_sum)
+}
+
+// Terriberry's single-pass update of the central moment sums M2, M3, M4
+// for an element added to a population of `n` (post-increment).
+#[inline]
+fn add_moment<T: Float + FromPrimitive>(
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+    new_element: T,
+    n: T,
+) -> (T, T, T, T) {
+    let delta = new_element - mean;
+    let delta_n = delta / n;
+    let delta_n2 = delta_n * delta_n;
+    let term1 = delta * delta_n * (n - T::one());
+
+    let new_mean = mean + delta_n;
+    let new_m4 = m4 + term1 * delta_n2 * (n * n - cast::<T>(3.0) * n + cast(3.0))
+        + cast::<T>(6.0) * delta_n2 * m2
+        - cast::<T>(4.0) * delta_n * m3;
+    let new_m3 = m3 + term1 * delta_n * (n - cast(2.0)) - cast::<T>(3.0) * delta_n * m2;
+    let new_m2 = m2 + term1;
+
+    (new_mean, new_m2, new_m3, new_m4)
+}
+
+// Exact inverse of `add_moment`: removes `element` from a population of
+// `n` (pre-removal), yielding the moment sums it had at `n - 1`.
+#[inline]
+fn remove_moment<T: Float + FromPrimitive>(
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+    element: T,
+    n: T,
+) -> (T, T, T, T) {
+    let delta_n = (element - mean) / (n - T::one());
+    let delta_n2 = delta_n * delta_n;
+    let delta = delta_n * n;
+    let term1 = delta * delta_n * (n - T::one());
+
+    let old_mean = mean - delta_n;
+    let old_m2 = m2 - term1;
+    let old_m3 = m3 - term1 * delta_n * (n - cast(2.0)) + cast::<T>(3.0) * delta_n * old_m2;
+    let old_m4 = m4 - term1 * delta_n2 * (n * n - cast::<T>(3.0) * n + cast(3.0))
+        - cast::<T>(6.0) * delta_n2 * old_m2
+        + cast::<T>(4.0) * delta_n * old_m3;
+
+    (old_mean, old_m2, old_m3, old_m4)
+}
+
+// Growing-phase counterpart of `growing_phase`, additionally tracking
+// M3/M4 so skewness and excess kurtosis can be derived.
+#[inline]
+fn growing_moments<T: Float + FromPrimitive>(
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+    new_element: T,
+    count: usize,
+) -> (T, T, T, T, T) {
+    if count < 2 {
+        return (new_element, T::zero(), T::zero(), T::zero(), T::zero());
+    }
+
+    let count_t: T = cast(count as f64);
+    let (new_mean, new_m2, new_m3, new_m4) = add_moment(mean, m2, m3, m4, new_element, count_t);
+    let stddev = (new_m2 / (count_t - T::one())).sqrt();
+
+    (new_mean, new_m2, new_m3, new_m4, stddev)
+}
+
+// Sliding-phase counterpart of `sliding_phase`: first removes the
+// ejected element's contribution, then adds the new one, so dropped
+// samples are exactly canceled out of M2/M3/M4.
+#[inline]
+fn sliding_moments<T: Float + FromPrimitive>(
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+    new_element: T,
+    ejected_element: T,
+    count: usize,
+) -> (T, T, T, T, T) {
+    let n: T = cast(count as f64);
+    let (mean, m2, m3, m4) = remove_moment(mean, m2, m3, m4, ejected_element, n);
+    let (new_mean, new_m2, new_m3, new_m4) = add_moment(mean, m2, m3, m4, new_element, n);
+    let stddev = (new_m2 / (n - T::one())).sqrt();
+
+    (new_mean, new_m2, new_m3, new_m4, stddev)
+}
+
+// Derive skewness and excess kurtosis from the moment sums, guarding
+// against a near-zero M2 (e.g. a constant window) where both are
+// otherwise undefined.
+#[inline]
+fn standardized_moments<T: Float + FromPrimitive>(
+    m2: T,
+    m3: T,
+    m4: T,
+    count: usize,
+) -> (Option<T>, Option<T>) {
+    if count < 2 || m2.abs() < T::epsilon() {
+        return (Some(T::zero()), Some(T::zero()));
+    }
+
+    let n: T = cast(count as f64);
+    let skewness = n.sqrt() * m3 / m2.powf(cast(1.5));
+    let excess_kurtosis = n * m4 / (m2 * m2) - cast(3.0);
+
+    (Some(skewness), Some(excess_kurtosis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // straightforward approach of computing mean and standard deviation
+    fn exact_stat(arr: &[f64]) -> (f64, f64) {
+        if arr.len() == 1 {
+            return (arr[0], 0_f64);
+        }
+
+        let mean = arr.iter().fold(0_f64, |acc, &v| acc + v) / arr.len() as f64;
+        let var =
+            arr.iter().fold(0_f64, |acc, &v| acc + (v - mean).powi(2)) / (arr.len() - 1) as f64;
+        (mean, var.sqrt())
+    }
+
+    fn in_delta(v1: f64, v2: f64, delta: f64) -> bool {
+        (v1 - v2).abs() < delta
+    }
+
+    fn exact_min_max(arr: &[f64]) -> (f64, f64) {
+        (
+            arr.iter().cloned().fold(f64::INFINITY, f64::min),
+            arr.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+
+    #[test]
+    fn bad_windows() {
+        assert!(StatWindow::<f64>::new(0).is_none());
+        assert!(StatWindow::<f64>::new(1).is_none());
+    }
+
+    #[test]
+    fn single_element() {
+        let mut sw = StatWindow::new(10).unwrap();
+
+        let val = 12.34;
+        let result = sw.push(val);
+
+        assert_eq!(result.mean, val);
+        assert_eq!(result.stddev, 0_f64);
+        assert_eq!(result.min, val);
+        assert_eq!(result.max, val);
+    }
+
+    #[test]
+    fn just_growing() {
+        let max_err = 1e-12;
+        let values: [f64; 4] = [1.0, 2.0, 4.0, 7.0];
+
+        // reference buffer
+        let mut growing_arr = Vec::new();
+        let mut sw = StatWindow::new(values.len()).unwrap();
+
+        for &v in values.iter() {
+            let InstantStat {
+                mean,
+                stddev,
+                min,
+                max,
+                ..
+            } = sw.push(v);
+
+            growing_arr.push(v);
+            let (em, es) = exact_stat(&growing_arr);
+            let (emin, emax) = exact_min_max(&growing_arr);
+
+            assert!(
+                in_delta(em, mean, max_err),
+                "mean => exact: {}, online: {}",
+                em,
+                mean
+            );
+            assert!(
+                in_delta(es, stddev, max_err),
+                "standard deviation => exact: {}, online: {}",
+                es,
+                stddev
+            );
+            assert_eq!(emin, min, "min => exact: {}, online: {}", emin, min);
+            assert_eq!(emax, max, "max => exact: {}, online: {}", emax, max);
+        }
+    }
+
+    #[test]
+    fn grow_and_slide() {
+        let max_err = 1e-12;
+        let win_size = 4_usize;
+        let values: [f64; 10] = [1.0, 2.0, 4.0, 4.0, 7.2, 12.5, 2.8, 3.1, 65.3, 98.01];
+
+        // reference buffer
+        let mut growing_arr = Vec::new();
+        let mut sw = StatWindow::new(win_size).unwrap();
+
+        // growth
+        for (step, &v) in values.iter().take(win_size).enumerate() {
+            let InstantStat {
+                mean,
+                stddev,
+                min,
+                max,
+                ..
+            } = sw.push(v);
+
+            growing_arr.push(v);
+            let (em, es) = exact_stat(&growing_arr);
+            let (emin, emax) = exact_min_max(&growing_arr);
+
+            assert!(
+                in_delta(em, mean, max_err),
+                "growth phase ({}), mean => exact: {}, online: {}",
+                step,
+                em,
+                mean
+            );
+            assert!(
+                in_delta(es, stddev, max_err),
+                "growth phase ({}), standard deviation => exact: {}, online: {}",
+                step,
+                es,
+                stddev
+            );
+            assert_eq!(emin, min, "growth phase ({}), min", step);
+            assert_eq!(emax, max, "growth phase ({}), max", step);
+        }
+
+        // sliding
+        for (step, &v) in values.iter().skip(win_size).enumerate() {
+            let InstantStat {
+                mean,
+                stddev,
+                min,
+                max,
+                ..
+            } = sw.push(v);
+
+            growing_arr.push(v);
+            let window = &growing_arr[step + 1..];
+            let (em, es) = exact_stat(window);
+            let (emin, emax) = exact_min_max(window);
+
+            assert!(
+                in_delta(em, mean, max_err),
+                "sliding phase ({}), mean => exact: {}, online: {}",
+                step,
+                em,
+                mean
+            );
+            assert!(
+                in_delta(es, stddev, max_err),
+                "sliding phase ({}), standard deviation => exact: {}, online: {}",
+                step,
+                es,
+                stddev
+            );
+            assert_eq!(emin, min, "sliding phase ({}), min", step);
+            assert_eq!(emax, max, "sliding phase ({}), max", step);
+        }
+    }
+
+    fn exact_skew_kurtosis(arr: &[f64]) -> (f64, f64) {
+        let n = arr.len() as f64;
+        let mean = arr.iter().fold(0_f64, |acc, &v| acc + v) / n;
+        let m2 = arr.iter().fold(0_f64, |acc, &v| acc + (v - mean).powi(2)) / n;
+        let m3 = arr.iter().fold(0_f64, |acc, &v| acc + (v - mean).powi(3)) / n;
+        let m4 = arr.iter().fold(0_f64, |acc, &v| acc + (v - mean).powi(4)) / n;
+        (m3 / m2.powf(1.5), m4 / (m2 * m2) - 3.0)
+    }
+
+    #[test]
+    fn plain_window_has_no_moments() {
+        let mut sw = StatWindow::new(4).unwrap();
+        let stat = sw.push(1.0);
+        assert!(stat.skewness.is_none());
+        assert!(stat.excess_kurtosis.is_none());
+    }
+
+    #[test]
+    fn skewness_and_kurtosis_growing_and_sliding() {
+        let max_err = 1e-9;
+        let win_size = 5_usize;
+        let values: [f64; 12] = [
+            1.0, 2.0, 4.0, 4.0, 7.2, 12.5, 2.8, 3.1, 65.3, 98.01, 0.5, 11.3,
+        ];
+
+        let mut growing_arr = Vec::new();
+        let mut sw = StatWindow::with_moments(win_size).unwrap();
+
+        for (step, &v) in values.iter().enumerate() {
+            let InstantStat {
+                skewness,
+                excess_kurtosis,
+                ..
+            } = sw.push(v);
+
+            growing_arr.push(v);
+            let window: &[f64] = if growing_arr.len() <= win_size {
+                &growing_arr
+            } else {
+                &growing_arr[growing_arr.len() - win_size..]
+            };
+
+            if window.len() < 2 {
+                continue;
+            }
+
+            let (eskew, ekurt) = exact_skew_kurtosis(window);
+
+            assert!(
+                in_delta(eskew, skewness.unwrap(), max_err),
+                "step {}, skewness => exact: {}, online: {}",
+                step,
+                eskew,
+                skewness.unwrap()
+            );
+            assert!(
+                in_delta(ekurt, excess_kurtosis.unwrap(), max_err),
+                "step {}, excess kurtosis => exact: {}, online: {}",
+                step,
+                ekurt,
+                excess_kurtosis.unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn f32_window_matches_f64_within_precision() {
+        let values: [f32; 6] = [1.0, 2.0, 4.0, 4.0, 7.2, 12.5];
+        let mut sw = StatWindow::<f32>::new(4).unwrap();
+
+        let mut last = None;
+        for &v in values.iter() {
+            last = Some(sw.push(v));
+        }
+
+        let stat = last.unwrap();
+        assert!((stat.mean - 6.925).abs() < 1e-3);
+    }
+
+    #[test]
+    fn nan_unaware_window_is_poisoned() {
+        let mut sw = StatWindow::new(4).unwrap();
+        sw.push(1.0);
+        let stat = sw.push(f64::NAN);
+        assert!(stat.mean.is_nan());
+    }
+
+    #[test]
+    fn nan_aware_window_ignores_missing_samples() {
+        let max_err = 1e-9;
+        let win_size = 4_usize;
+        let values = [1.0, f64::NAN, 2.0, f64::NAN, 4.0, 7.0, f64::NAN, 9.0, 3.0];
+
+        let mut growing_arr: Vec<f64> = Vec::new();
+        let mut sw = StatWindow::with_nan_handling(win_size).unwrap();
+
+        for &v in values.iter() {
+            let InstantStat { mean, stddev, .. } = sw.push(v);
+
+            growing_arr.push(v);
+            let window_start = growing_arr.len().saturating_sub(win_size);
+            let finite: Vec<f64> = growing_arr[window_start..]
+                .iter()
+                .cloned()
+                .filter(|x| !x.is_nan())
+                .collect();
+
+            if finite.is_empty() {
+                assert!(mean.is_nan());
+                assert_eq!(stddev, 0.0);
+                continue;
+            }
+
+            let (em, es) = exact_stat(&finite);
+            assert!(
+                in_delta(em, mean, max_err),
+                "mean => exact: {}, online: {}",
+                em,
+                mean
+            );
+            assert!(
+                in_delta(es, stddev, max_err),
+                "standard deviation => exact: {}, online: {}",
+                es,
+                stddev
+            );
+        }
+    }
+
+    #[test]
+    fn nan_aware_window_recovers_after_emptying() {
+        // Window of 2 drains down to zero finite samples (the 5.0 ages
+        // out behind two NaNs) and then refills with finite values; the
+        // internal running mean must not get poisoned by the NaN display
+        // value produced while the window was empty.
+        let mut sw = StatWindow::with_nan_handling(2).unwrap();
+
+        sw.push(5.0);
+        sw.push(f64::NAN);
+        let stat = sw.push(f64::NAN);
+        assert!(stat.mean.is_nan());
+
+        let stat = sw.push(10.0);
+        assert_eq!(stat.mean, 10.0);
+
+        let stat = sw.push(20.0);
+        assert_eq!(stat.mean, 15.0);
+    }
+}
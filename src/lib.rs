@@ -17,16 +17,17 @@
 //! let window_size = 5;
 //!
 //! // create estimator
-//! let mut sw = StatWindow::new(window_size);
+//! let sw = StatWindow::new(window_size);
 //!
 //! // ensure it was created
 //! if sw.is_none() { println!("bad window size") }
+//! let mut sw = sw.unwrap();
 //!
 //! // add values on the fly
 //! let values: [f64; 8] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
 //! for &v in values.iter() {
-//!     let (mean, stddev) = sw.push(v);
-//!     println!("add {}, window stats => mean: {}, standard deviation: {}", v, mean, stddev);
+//!     let stat = sw.push(v);
+//!     println!("add {}, window stats => mean: {}, standard deviation: {}", v, stat.mean, stat.stddev);
 //! }
 //! ```
 //!
@@ -35,6 +36,30 @@
 //! to the window size and there are no allocations taking place after buffer
 //! was initially created.
 //!
+//! `StatWindow` is generic over the float type it stores (`f64` by
+//! default, via inference); use `StatWindow::<f32>::new(...)` when a
+//! large window should use half the memory.
+//!
+//! Skewness and excess kurtosis can additionally be tracked by creating
+//! the window with [`StatWindow::with_moments`] instead of `new`; the
+//! plain constructor keeps the cheap mean/stddev path free of the extra
+//! bookkeeping.
+//!
+//! Real feeds sometimes carry gaps as NaN; [`StatWindow::with_nan_handling`]
+//! creates a window that keeps such pushes in the buffer (so they still
+//! age out normally) while excluding them from `mean`/`stddev`, which are
+//! computed over the finite samples currently in the window.
+//!
+//! For full-series statistics computed over large data split across
+//! threads, [`StatAccumulator`] offers the batch counterpart: accumulate
+//! each chunk independently and [`merge`](StatAccumulator::merge) the
+//! results together.
+//!
+//! `StatWindow<f64>` also implements the [`View`] trait, emitting the
+//! z-score of each pushed value; [`Chain`] composes two views so one
+//! transform's output feeds the next, e.g. a rolling standardization
+//! feeding a rolling mean.
+//!
 //! Under the hood statistics estimator operates in the two phases:
 //! 1. growing buffer
 //! 2. sliding window
@@ -50,10 +75,14 @@
 //! contribution of previously removed elements.
 //!
 
+mod accumulator;
 mod estimator;
+mod view;
 
 // re-export
+pub use accumulator::StatAccumulator;
 pub use estimator::{InstantStat, StatWindow};
+pub use view::{Chain, View};
 
 #[cfg(test)]
 mod tests {
@@ -65,7 +94,7 @@ mod tests {
         let mut sw = StatWindow::new(5).unwrap();
 
         let value: f64 = 5.2;
-        let res: Vec<InstantStat> = repeat(value).take(10).map(|v| sw.push(v)).collect();
+        let res: Vec<InstantStat<f64>> = repeat(value).take(10).map(|v| sw.push(v)).collect();
 
         for s in res.iter() {
             assert_eq!(s.mean, value);
@@ -0,0 +1,106 @@
+use crate::StatWindow;
+
+/// A stateful online transform that consumes values one at a time and
+/// exposes its most recent output, so transforms can be chained into a
+/// small pipeline (see [`Chain`]).
+pub trait View {
+    /// Feed a new value into the transform.
+    fn update(&mut self, value: f64);
+
+    /// Most recent output of the transform.
+    fn last(&self) -> f64;
+}
+
+impl View for StatWindow<f64> {
+    /// Pushes `value` into the window and records its z-score,
+    /// `(value - mean) / stddev`, as the view's output.
+    fn update(&mut self, value: f64) {
+        let stat = self.push(value);
+        self.last_z = if stat.stddev > 0.0 {
+            (value - stat.mean) / stat.stddev
+        } else {
+            0.0
+        };
+    }
+
+    fn last(&self) -> f64 {
+        self.last_z
+    }
+}
+
+/// Pipes the output of one [`View`] into another, so e.g. a rolling
+/// standardization can feed a downstream rolling window.
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: View, B: View> Chain<A, B> {
+    /// Combine two views: `first`'s output becomes `second`'s input.
+    pub fn new(first: A, second: B) -> Self {
+        Chain { first, second }
+    }
+}
+
+impl<A: View, B: View> View for Chain<A, B> {
+    fn update(&mut self, value: f64) {
+        self.first.update(value);
+        self.second.update(self.first.last());
+    }
+
+    fn last(&self) -> f64 {
+        self.second.last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn z_score(window: &mut StatWindow<f64>, value: f64) -> f64 {
+        let stat = window.push(value);
+        if stat.stddev > 0.0 {
+            (value - stat.mean) / stat.stddev
+        } else {
+            0.0
+        }
+    }
+
+    #[test]
+    fn view_emits_z_score() {
+        let mut sw = StatWindow::<f64>::new(4).unwrap();
+        let mut reference = StatWindow::<f64>::new(4).unwrap();
+
+        for &v in [1.0, 2.0, 4.0, 8.0, 3.0].iter() {
+            sw.update(v);
+            let expected = z_score(&mut reference, v);
+            assert!((sw.last() - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn chain_feeds_downstream_view() {
+        let win_size = 4;
+        let mut chain = Chain::new(
+            StatWindow::<f64>::new(win_size).unwrap(),
+            StatWindow::<f64>::new(win_size).unwrap(),
+        );
+
+        let mut reference_first = StatWindow::<f64>::new(win_size).unwrap();
+        let mut reference_second = StatWindow::<f64>::new(win_size).unwrap();
+
+        for &v in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].iter() {
+            chain.update(v);
+
+            let z1 = z_score(&mut reference_first, v);
+            let z2 = z_score(&mut reference_second, z1);
+
+            assert!(
+                (chain.last() - z2).abs() < 1e-12,
+                "expected: {}, got: {}",
+                z2,
+                chain.last()
+            );
+        }
+    }
+}